@@ -5,7 +5,8 @@ use nu_engine::{eval_block, CallExt};
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{CaptureBlock, Command, EngineState, Stack};
 use nu_protocol::{
-    Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape, Value,
+    Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape, Type,
+    Value,
 };
 
 #[derive(Clone)]
@@ -18,6 +19,11 @@ impl Command for Reduce {
 
     fn signature(&self) -> Signature {
         Signature::build("reduce")
+            .input_output_types(vec![
+                (Type::List(Box::new(Type::Any)), Type::Any),
+                (Type::Table(vec![]), Type::Any),
+                (Type::Range, Type::Any),
+            ])
             .named(
                 "fold",
                 SyntaxShape::Any,
@@ -30,6 +36,7 @@ impl Command for Reduce {
                 "reducing function",
             )
             .switch("numbered", "iterate with an index", Some('n'))
+            .switch("right", "fold from right to left", Some('r'))
     }
 
     fn usage(&self) -> &str {
@@ -88,6 +95,38 @@ impl Command for Reduce {
                     span: Span::test_data(),
                 }),
             },
+            Example {
+                example: "[ 1 2 0 4 5 ] | reduce -f 1 {|it, acc| if $it == 0 { return 0 }; $acc * $it }",
+                description: "Stop folding early with `return` once a terminal value is reached",
+                result: Some(Value::Int {
+                    val: 0,
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                example: r#"[ a b c ] | reduce -r -f "" {|it, acc| $acc + $it }"#,
+                description: "Fold from right to left, building the string back to front",
+                result: Some(Value::String {
+                    val: "cba".to_string(),
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                example: "[ 10 20 30 ] | reduce -r -n {|it, acc| if $it.index == 1 { $it.item } else { $acc } }",
+                description: "With --right, $it.index still reflects the original left-to-right position",
+                result: Some(Value::Int {
+                    val: 20,
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                example: "1..10 | reduce {|it, acc| $it + $acc }",
+                description: "Sum values of a range (same as 'math sum')",
+                result: Some(Value::Int {
+                    val: 55,
+                    span: Span::test_data(),
+                }),
+            },
         ]
     }
 
@@ -110,26 +149,71 @@ impl Command for Reduce {
         let orig_env_vars = stack.env_vars.clone();
         let orig_env_hidden = stack.env_hidden.clone();
 
+        // ranges aren't directly iterable, so materialize them into a stream
+        // of values up front and fold over that the same way we would a list
+        let input = match input {
+            PipelineData::Value(Value::Range { val: range, .. }, ..) => range
+                .into_range_iter(ctrlc.clone())?
+                .into_pipeline_data(ctrlc.clone()),
+            input => input,
+        };
+
         let redirect_stdout = call.redirect_stdout;
         let redirect_stderr = call.redirect_stderr;
 
-        let mut input_iter = input.into_iter();
+        let right = call.has_flag("right");
+
+        let (start_val, items): (Value, Box<dyn Iterator<Item = (i64, Value)>>) = if right {
+            // for a right fold we need the whole input up front so we can walk
+            // it back to front, so buffer it into a vec instead of streaming
+            let mut values: Vec<Value> = input.into_iter().collect();
+
+            let start_val = if let Some(val) = fold {
+                val
+            } else if let Some(val) = values.pop() {
+                val
+            } else {
+                return Err(ShellError::SpannedLabeledError(
+                    "Expected input".to_string(),
+                    "needs input".to_string(),
+                    span,
+                ));
+            };
+
+            let items = values
+                .into_iter()
+                .enumerate()
+                .map(|(idx, x)| (idx as i64, x))
+                .rev();
 
-        let (off, start_val) = if let Some(val) = fold {
-            (0, val)
-        } else if let Some(val) = input_iter.next() {
-            (1, val)
+            (start_val, Box::new(items))
         } else {
-            return Err(ShellError::SpannedLabeledError(
-                "Expected input".to_string(),
-                "needs input".to_string(),
-                span,
-            ));
+            // no buffering here: keep streaming off of `input_iter` so a
+            // `return` inside the block can short-circuit a lazy/infinite input
+            let mut input_iter = input.into_iter();
+
+            let (off, start_val) = if let Some(val) = fold {
+                (0, val)
+            } else if let Some(val) = input_iter.next() {
+                (1, val)
+            } else {
+                return Err(ShellError::SpannedLabeledError(
+                    "Expected input".to_string(),
+                    "needs input".to_string(),
+                    span,
+                ));
+            };
+
+            let items = input_iter
+                .enumerate()
+                .map(move |(idx, x)| (idx as i64 + off, x));
+
+            (start_val, Box::new(items))
         };
 
         let mut acc = start_val;
 
-        for (idx, x) in input_iter.enumerate() {
+        for (idx, x) in items {
             stack.with_env(&orig_env_vars, &orig_env_hidden);
             // if the acc coming from previous iter is indexed, drop the index
             acc = if let Value::Record { cols, vals, .. } = &acc {
@@ -151,13 +235,7 @@ impl Command for Reduce {
                     let it = if numbered {
                         Value::Record {
                             cols: vec!["index".to_string(), "item".to_string()],
-                            vals: vec![
-                                Value::Int {
-                                    val: idx as i64 + off,
-                                    span,
-                                },
-                                x,
-                            ],
+                            vals: vec![Value::Int { val: idx, span }, x],
                             span,
                         }
                     } else {
@@ -173,15 +251,23 @@ impl Command for Reduce {
                 }
             }
 
-            acc = eval_block(
+            acc = match eval_block(
                 engine_state,
                 &mut stack,
                 block,
                 PipelineData::new(span),
                 redirect_stdout,
                 redirect_stderr,
-            )?
-            .into_value(span);
+            ) {
+                Ok(pipeline_data) => pipeline_data.into_value(span),
+                // when the block contains a `return`, propagate the returned
+                // value as the accumulator and stop folding immediately
+                Err(ShellError::Return(_, value)) => {
+                    acc = *value;
+                    break;
+                }
+                Err(err) => return Err(err),
+            };
 
             if let Some(ctrlc) = &ctrlc {
                 if ctrlc.load(Ordering::SeqCst) {